@@ -0,0 +1,143 @@
+use std::io;
+use std::net::SocketAddr;
+
+use tokio::io::{AsyncRead, AsyncWrite};
+
+use crate::tls::TlsConfig;
+
+pub mod tls;
+pub mod quic;
+
+/// A listener that can be bound to produce a [`Listener`].
+#[crate::async_trait]
+pub trait Bindable: Send + Sized {
+    /// The [`Listener`] produced by [`Bindable::bind()`].
+    type Listener: Listener;
+
+    /// The error that can occur when binding.
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// Binds this `Bindable`, producing a [`Listener`].
+    async fn bind(self) -> Result<Self::Listener, Self::Error>;
+
+    /// Returns the [`Endpoint`] this `Bindable` will listen on without
+    /// actually binding. Used to report the endpoint before `bind()`
+    /// succeeds or to construct one ahead of time, e.g. for logging.
+    fn bind_endpoint(&self) -> io::Result<Endpoint>;
+}
+
+/// A bound listener that accepts raw connections and upgrades them.
+#[crate::async_trait]
+pub trait Listener: Send + Sync + 'static {
+    /// A raw, not-yet-upgraded accepted connection.
+    type Accept: Send;
+
+    /// The upgraded connection produced by [`Listener::connect()`].
+    type Connection: Connection;
+
+    /// Accepts a single raw connection.
+    async fn accept(&self) -> io::Result<Self::Accept>;
+
+    /// Upgrades a raw connection into a [`Listener::Connection`], e.g. by
+    /// performing a TLS or QUIC handshake.
+    async fn connect(&self, accept: Self::Accept) -> io::Result<Self::Connection>;
+
+    /// Returns the [`Endpoint`] this listener is bound to.
+    fn endpoint(&self) -> io::Result<Endpoint>;
+}
+
+/// An upgraded, request-ready connection.
+pub trait Connection: AsyncRead + AsyncWrite + Unpin + Send + 'static {
+    /// Returns the [`Endpoint`] this connection was accepted on.
+    fn endpoint(&self) -> io::Result<Endpoint>;
+
+    /// Returns the peer's TLS client certificate chain, if any, and if this
+    /// was built with the `mtls` feature.
+    #[cfg(feature = "mtls")]
+    fn certificates(&self) -> Option<Certificates<'_>> {
+        None
+    }
+
+    /// Whether the bytes currently being read from this connection arrived
+    /// as TLS 1.3 0-RTT early data, and so are replayable by a network
+    /// attacker. Routes must not treat such bytes as trustworthy for
+    /// non-idempotent requests. Defaults to `false`.
+    fn is_early_data(&self) -> bool {
+        false
+    }
+}
+
+/// A peer's TLS client certificate chain, borrowed from the connection.
+#[cfg(feature = "mtls")]
+pub struct Certificates<'a>(&'a [rustls::pki_types::CertificateDer<'static>]);
+
+#[cfg(feature = "mtls")]
+impl<'a> From<&'a [rustls::pki_types::CertificateDer<'static>]> for Certificates<'a> {
+    fn from(chain: &'a [rustls::pki_types::CertificateDer<'static>]) -> Self {
+        Certificates(chain)
+    }
+}
+
+/// Metadata about a listener or connection's endpoint: its address and the
+/// transport-level properties (TLS, QUIC, early data) layered on top of it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Endpoint {
+    /// The socket address, if the underlying transport has one.
+    pub address: Option<SocketAddr>,
+    /// Whether this endpoint is TLS-protected.
+    pub tls: bool,
+    /// Whether this endpoint is QUIC/UDP-based rather than a TCP byte stream.
+    pub quic: bool,
+    /// Whether the associated connection's current data is 0-RTT early data.
+    pub early_data: bool,
+}
+
+impl Endpoint {
+    /// Returns a plain, non-TLS, non-QUIC endpoint with no address.
+    pub fn new() -> Self {
+        Endpoint { address: None, tls: false, quic: false, early_data: false }
+    }
+
+    /// Marks this endpoint as TLS-protected, configured by `config`.
+    pub fn with_tls(mut self, _config: &TlsConfig) -> Self {
+        self.tls = true;
+        self
+    }
+
+    /// Marks this endpoint as TLS-protected without reference to a
+    /// particular [`TlsConfig`], e.g. because it's already wrapped.
+    pub fn assume_tls(mut self) -> Self {
+        self.tls = true;
+        self
+    }
+
+    /// Marks this endpoint as QUIC/UDP-based rather than a TCP byte stream.
+    pub fn with_quic(mut self) -> Self {
+        self.quic = true;
+        self
+    }
+
+    /// Sets whether the associated connection's current data arrived as
+    /// 0-RTT early data. See [`Connection::is_early_data()`].
+    pub fn with_early_data(mut self, early_data: bool) -> Self {
+        self.early_data = early_data;
+        self
+    }
+
+    /// Whether this endpoint's connection's current data is early data.
+    pub fn is_early_data(&self) -> bool {
+        self.early_data
+    }
+}
+
+impl Default for Endpoint {
+    fn default() -> Self {
+        Endpoint::new()
+    }
+}
+
+impl From<SocketAddr> for Endpoint {
+    fn from(address: SocketAddr) -> Self {
+        Endpoint { address: Some(address), ..Endpoint::new() }
+    }
+}
@@ -0,0 +1,203 @@
+use std::io;
+use std::sync::Arc;
+
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::sync::mpsc;
+
+use crate::tls::{Error, TlsConfig};
+use crate::listener::{Bindable, Listener, Connection, Certificates, Endpoint};
+
+/// How many accepted streams, across all of a `QuicListener`'s connections,
+/// may wait to be returned by [`QuicListener::accept()`] before a peer
+/// opening new streams has to wait.
+const STREAM_BACKLOG: usize = 1024;
+
+/// A QUIC/HTTP3 listener, built from the same [`TlsConfig`] as
+/// [`TlsBindable`](crate::listener::tls::TlsBindable) but driving a `quinn`
+/// endpoint instead of `tokio_rustls` over TCP.
+///
+/// Because QUIC's crypto handshake *is* TLS 1.3, `certs`, `key`, `alpn`, and
+/// the mTLS [`ClientAuth`](crate::tls::ClientAuth) policy all carry over
+/// unchanged: `QuicBindable` turns the configured [`TlsConfig`] into a
+/// [`quinn::crypto::rustls::QuicServerConfig`] via the same
+/// [`TlsConfig::to_server_config()`] used for TCP. That crypto configuration
+/// is built once, at bind time; a `QuicListener` does not consult a
+/// [`Resolver`](crate::tls::Resolver) during the handshake.
+///
+/// Swap a [`TlsBindable`](crate::listener::tls::TlsBindable) for a
+/// `QuicBindable` to serve HTTP/3 instead of HTTP/1.1 or HTTP/2 over TCP;
+/// the rest of Rocket — routes, fairings, `Certificates` guards — is
+/// unaware of the transport underneath.
+#[derive(Clone)]
+pub struct QuicBindable {
+    /// The local address to bind the UDP socket to.
+    pub address: std::net::SocketAddr,
+    /// The TLS configuration shared with TCP-based listeners.
+    pub tls: TlsConfig,
+}
+
+pub struct QuicListener {
+    address: std::net::SocketAddr,
+    tls: TlsConfig,
+    streams: tokio::sync::Mutex<mpsc::Receiver<io::Result<QuicStream>>>,
+}
+
+impl Bindable for QuicBindable {
+    type Listener = QuicListener;
+
+    type Error = Error;
+
+    async fn bind(self) -> Result<Self::Listener, Self::Error> {
+        let server_config = self.tls.to_server_config()?;
+        let quic_crypto = quinn::crypto::rustls::QuicServerConfig::try_from(server_config)
+            .map_err(|e| Error::Bind(Box::new(e)))?;
+
+        let server_config = quinn::ServerConfig::with_crypto(Arc::new(quic_crypto));
+        let endpoint = quinn::Endpoint::server(server_config, self.address)
+            .map_err(|e| Error::Bind(Box::new(e)))?;
+
+        let (tx, rx) = mpsc::channel(STREAM_BACKLOG);
+        tokio::spawn(drive_connections(endpoint, tx));
+
+        Ok(QuicListener {
+            address: self.address,
+            tls: self.tls,
+            streams: tokio::sync::Mutex::new(rx),
+        })
+    }
+
+    fn bind_endpoint(&self) -> io::Result<Endpoint> {
+        Ok(Endpoint::from(self.address).with_tls(&self.tls).with_quic())
+    }
+}
+
+/// Accepts every incoming QUIC connection on `endpoint`, then, for each one,
+/// accepts every bidirectional stream the peer opens on it, forwarding each
+/// as a [`QuicStream`] over `tx`. HTTP/3 multiplexes many requests over a
+/// single `quinn::Connection`'s streams, so `accept_bi()` must be called in
+/// a loop for the connection's whole lifetime rather than once — this task
+/// is that loop, run concurrently for every connection the endpoint accepts.
+async fn drive_connections(endpoint: quinn::Endpoint, tx: mpsc::Sender<io::Result<QuicStream>>) {
+    while let Some(incoming) = endpoint.accept().await {
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            let connection = match incoming.await {
+                Ok(connection) => connection,
+                Err(e) => {
+                    let _ = tx.send(Err(io::Error::new(io::ErrorKind::ConnectionAborted, e))).await;
+                    return;
+                }
+            };
+
+            loop {
+                let stream = connection.accept_bi().await
+                    .map(|(send, recv)| QuicStream::new(connection.clone(), send, recv))
+                    .map_err(|e| io::Error::new(io::ErrorKind::ConnectionAborted, e));
+
+                let is_err = stream.is_err();
+                if tx.send(stream).await.is_err() || is_err {
+                    break;
+                }
+            }
+        });
+    }
+}
+
+impl Listener for QuicListener {
+    type Accept = QuicStream;
+
+    type Connection = QuicStream;
+
+    async fn accept(&self) -> io::Result<Self::Accept> {
+        self.streams.lock().await.recv().await
+            .ok_or_else(|| io::Error::new(io::ErrorKind::BrokenPipe, "QUIC endpoint closed"))?
+    }
+
+    async fn connect(&self, stream: Self::Accept) -> io::Result<Self::Connection> {
+        Ok(stream)
+    }
+
+    fn endpoint(&self) -> io::Result<Endpoint> {
+        Ok(Endpoint::from(self.address).with_tls(&self.tls).with_quic())
+    }
+}
+
+/// A single bidirectional QUIC stream, presented as a byte stream so the
+/// rest of Rocket can treat it like any other [`Connection`].
+///
+/// HTTP/3 multiplexes many requests over one `quinn::Connection`'s streams;
+/// each stream a peer opens is accepted by a background task (spawned in
+/// [`QuicBindable::bind()`](Bindable::bind)) that calls
+/// `quinn::Connection::accept_bi()` in a loop for that connection's whole
+/// lifetime, so every request stream — not just the first — reaches
+/// [`QuicListener::accept()`] as its own `QuicStream`, mirroring how one
+/// accepted TCP connection corresponds to one connection in
+/// [`TlsListener`](crate::listener::tls::TlsListener).
+pub struct QuicStream {
+    connection: quinn::Connection,
+    send: quinn::SendStream,
+    recv: quinn::RecvStream,
+    #[cfg(feature = "mtls")]
+    peer_certs: Option<Arc<Vec<rustls::pki_types::CertificateDer<'static>>>>,
+}
+
+impl QuicStream {
+    fn new(connection: quinn::Connection, send: quinn::SendStream, recv: quinn::RecvStream) -> Self {
+        QuicStream {
+            #[cfg(feature = "mtls")]
+            peer_certs: connection.peer_identity()
+                .and_then(|identity| identity
+                    .downcast::<Vec<rustls::pki_types::CertificateDer<'static>>>()
+                    .ok())
+                .map(|certs| Arc::new(*certs)),
+            connection,
+            send,
+            recv,
+        }
+    }
+}
+
+impl AsyncRead for QuicStream {
+    fn poll_read(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> std::task::Poll<io::Result<()>> {
+        std::pin::Pin::new(&mut self.recv).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for QuicStream {
+    fn poll_write(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+        buf: &[u8],
+    ) -> std::task::Poll<io::Result<usize>> {
+        std::pin::Pin::new(&mut self.send).poll_write(cx, buf)
+    }
+
+    fn poll_flush(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<io::Result<()>> {
+        std::pin::Pin::new(&mut self.send).poll_flush(cx)
+    }
+
+    fn poll_shutdown(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<io::Result<()>> {
+        std::pin::Pin::new(&mut self.send).poll_shutdown(cx)
+    }
+}
+
+impl Connection for QuicStream {
+    fn endpoint(&self) -> io::Result<Endpoint> {
+        Ok(Endpoint::from(self.connection.remote_address()).assume_tls().with_quic())
+    }
+
+    #[cfg(feature = "mtls")]
+    fn certificates(&self) -> Option<Certificates<'_>> {
+        Some(Certificates::from(self.peer_certs.as_deref()?.as_slice()))
+    }
+}
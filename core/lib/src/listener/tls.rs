@@ -1,16 +1,26 @@
-use std::io;
+use std::io::{self, Read};
+use std::collections::VecDeque;
+use std::pin::Pin;
 use std::sync::Arc;
+use std::task::{Context, Poll};
 
 use serde::Deserialize;
-use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
 use tokio_rustls::LazyConfigAcceptor;
 use rustls::server::{Acceptor, ServerConfig};
 
 use crate::tls::{Error, Resolver, TlsConfig};
 use crate::listener::{Listener, Bindable, Connection, Certificates, Endpoint};
 
-#[doc(inline)]
-pub use tokio_rustls::server::TlsStream;
+/// A TLS stream, wrapping [`tokio_rustls::server::TlsStream`] to additionally
+/// buffer any TLS 1.3 0-RTT early data the client sent, so that it can be
+/// read back out through the ordinary [`AsyncRead`] implementation while
+/// still being distinguishable, via [`Connection::is_early_data()`], from
+/// data exchanged after the handshake completed.
+pub struct TlsStream<C> {
+    inner: tokio_rustls::server::TlsStream<C>,
+    early_data: VecDeque<u8>,
+}
 
 /// A TLS listener over some listener interface L.
 pub struct TlsListener<L> {
@@ -70,7 +80,21 @@ impl<L> Listener for TlsListener<L>
             None => self.default.clone(),
         };
 
-        handshake.into_stream(config).await
+        let mut inner = handshake.into_stream(config).await?;
+
+        // Any 0-RTT early data the client sent arrives decrypted into the
+        // connection's own early-data buffer, separate from the ordinary
+        // plaintext stream `poll_read()` returns — that separation *is* the
+        // signal that these particular bytes are replayable, so drain it
+        // into ours up front and serve it back out first.
+        let mut early_data = VecDeque::new();
+        if let Some(mut reader) = inner.get_mut().1.early_data() {
+            let mut buf = Vec::new();
+            reader.read_to_end(&mut buf)?;
+            early_data.extend(buf);
+        }
+
+        Ok(TlsStream { inner, early_data })
     }
 
     fn endpoint(&self) -> io::Result<Endpoint> {
@@ -78,14 +102,61 @@ impl<L> Listener for TlsListener<L>
     }
 }
 
+impl<C: AsyncRead + AsyncWrite + Unpin> AsyncRead for TlsStream<C> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        if !self.early_data.is_empty() {
+            let (front, _) = self.early_data.as_slices();
+            let n = std::cmp::min(front.len(), buf.remaining());
+            buf.put_slice(&front[..n]);
+            self.early_data.drain(..n);
+            return Poll::Ready(Ok(()));
+        }
+
+        Pin::new(&mut self.inner).poll_read(cx, buf)
+    }
+}
+
+impl<C: AsyncRead + AsyncWrite + Unpin> AsyncWrite for TlsStream<C> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+}
+
 impl<C: Connection> Connection for TlsStream<C> {
     fn endpoint(&self) -> io::Result<Endpoint> {
-        Ok(self.get_ref().0.endpoint()?.assume_tls())
+        Ok(self.inner.get_ref().0.endpoint()?.assume_tls().with_early_data(self.is_early_data()))
     }
 
     #[cfg(feature = "mtls")]
     fn certificates(&self) -> Option<Certificates<'_>> {
-        let cert_chain = self.get_ref().1.peer_certificates()?;
+        let cert_chain = self.inner.get_ref().1.peer_certificates()?;
         Some(Certificates::from(cert_chain))
     }
+
+    /// Whether the bytes the next [`AsyncRead::poll_read()`] call returns
+    /// come from the buffer [`TlsListener::connect()`] drained out of the
+    /// connection's 0-RTT early-data reader. This empties as soon as that
+    /// buffer does, so it's only ever `true` for the handful of reads at the
+    /// very start of the connection that actually serve replayable bytes —
+    /// never for the rest of the connection, including every subsequent
+    /// request multiplexed over the same keep-alive stream.
+    fn is_early_data(&self) -> bool {
+        !self.early_data.is_empty()
+    }
 }
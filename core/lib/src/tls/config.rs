@@ -0,0 +1,322 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use either::Either;
+use serde::{Deserialize, Serialize};
+use rustls::ServerConfig;
+use rustls::server::NoClientAuth;
+#[cfg(feature = "mtls")]
+use rustls::server::WebPkiClientVerifier;
+
+use crate::tls::{Error, util};
+
+/// TLS configuration: certificate chain, private key, and related options.
+///
+/// `certs` and `key` can each be a path on disk or a raw
+/// PEM-encoded byte buffer; the latter is primarily useful for embedding
+/// certificates at compile-time or constructing a `TlsConfig` entirely in
+/// code, for instance for use with a [`Resolver`](crate::tls::Resolver).
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct TlsConfig {
+    #[serde(with = "either::serde_untagged")]
+    pub(crate) certs: Either<PathBuf, Vec<u8>>,
+    #[serde(with = "either::serde_untagged")]
+    pub(crate) key: Either<PathBuf, Vec<u8>>,
+    #[serde(default = "CipherSuite::default_set")]
+    pub(crate) ciphersuites: Vec<CipherSuite>,
+    #[serde(default)]
+    pub(crate) prefer_server_cipher_order: bool,
+    #[serde(default)]
+    pub(crate) alpn: Vec<String>,
+    #[serde(default, with = "self::ocsp_serde")]
+    pub(crate) ocsp: Option<Either<PathBuf, Vec<u8>>>,
+    #[serde(default)]
+    pub(crate) early_data: bool,
+    #[cfg(feature = "mtls")]
+    #[serde(default)]
+    pub(crate) client_auth: ClientAuth,
+}
+
+/// (De)serializes `Option<Either<PathBuf, Vec<u8>>>` the same untagged way
+/// `either::serde_untagged` does for the non-optional `certs`/`key` fields.
+mod ocsp_serde {
+    use std::path::PathBuf;
+    use either::Either;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Repr { Path(PathBuf), Bytes(Vec<u8>) }
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<Either<PathBuf, Vec<u8>>>,
+        ser: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(Either::Left(path)) => ser.serialize_some(path),
+            Some(Either::Right(bytes)) => ser.serialize_some(bytes),
+            None => ser.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        de: D,
+    ) -> Result<Option<Either<PathBuf, Vec<u8>>>, D::Error> {
+        let repr = Option::<Repr>::deserialize(de)?;
+        Ok(repr.map(|r| match r {
+            Repr::Path(p) => Either::Left(p),
+            Repr::Bytes(b) => Either::Right(b),
+        }))
+    }
+}
+
+/// A supported TLS cipher suite.
+#[derive(Debug, PartialEq, Copy, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum CipherSuite {
+    TLS_CHACHA20_POLY1305_SHA256,
+    TLS_AES_256_GCM_SHA384,
+    TLS_AES_128_GCM_SHA256,
+    TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384,
+    TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256,
+    TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256,
+    TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+    TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+    TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256,
+}
+
+impl CipherSuite {
+    pub(crate) const DEFAULT_SET: &'static [CipherSuite] = &[
+        CipherSuite::TLS_CHACHA20_POLY1305_SHA256,
+        CipherSuite::TLS_AES_256_GCM_SHA384,
+        CipherSuite::TLS_AES_128_GCM_SHA256,
+        CipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384,
+        CipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256,
+        CipherSuite::TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256,
+        CipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+        CipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+        CipherSuite::TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256,
+    ];
+
+    pub(crate) fn default_set() -> Vec<CipherSuite> {
+        CipherSuite::DEFAULT_SET.to_vec()
+    }
+
+    /// The `rustls` cipher suite identifier this variant corresponds to.
+    fn to_rustls(self) -> rustls::CipherSuite {
+        match self {
+            CipherSuite::TLS_CHACHA20_POLY1305_SHA256 => rustls::CipherSuite::TLS13_CHACHA20_POLY1305_SHA256,
+            CipherSuite::TLS_AES_256_GCM_SHA384 => rustls::CipherSuite::TLS13_AES_256_GCM_SHA384,
+            CipherSuite::TLS_AES_128_GCM_SHA256 => rustls::CipherSuite::TLS13_AES_128_GCM_SHA256,
+            CipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384 => rustls::CipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384,
+            CipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256 => rustls::CipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256,
+            CipherSuite::TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256 => rustls::CipherSuite::TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256,
+            CipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384 => rustls::CipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+            CipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256 => rustls::CipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+            CipherSuite::TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256 => rustls::CipherSuite::TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256,
+        }
+    }
+}
+
+/// Mutual-TLS client certificate verification policy.
+///
+/// Controls how, if at all, the server asks the client for a certificate
+/// during the handshake. This is independent of the [`Certificates`]
+/// request guard, which simply reads whatever the handshake already
+/// negotiated: a route using `Certificates` should pair it with
+/// [`ClientAuth::Required`] if the client certificate must be guaranteed
+/// present, or branch on `Option` when paired with [`ClientAuth::Optional`].
+///
+/// [`Certificates`]: crate::listener::Certificates
+#[cfg(feature = "mtls")]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum ClientAuth {
+    /// Do not request a client certificate. The default.
+    Off,
+    /// Request a client certificate but allow the handshake to proceed
+    /// without one; the presented certificate, if any, is validated against
+    /// `ca`.
+    Optional {
+        /// A PEM-encoded CA certificate bundle, as a path or raw bytes.
+        #[serde(with = "either::serde_untagged")]
+        ca: Either<PathBuf, Vec<u8>>,
+    },
+    /// Require the client to present a certificate that validates against
+    /// `ca`; the handshake fails otherwise.
+    Required {
+        /// A PEM-encoded CA certificate bundle, as a path or raw bytes.
+        #[serde(with = "either::serde_untagged")]
+        ca: Either<PathBuf, Vec<u8>>,
+    },
+}
+
+#[cfg(feature = "mtls")]
+impl Default for ClientAuth {
+    fn default() -> Self {
+        ClientAuth::Off
+    }
+}
+
+impl TlsConfig {
+    /// Creates a new `TlsConfig` from `certs` and `key` byte buffers.
+    pub fn from_bytes(certs: &[u8], key: &[u8]) -> Self {
+        TlsConfig {
+            certs: Either::Right(certs.to_vec()),
+            key: Either::Right(key.to_vec()),
+            ciphersuites: CipherSuite::DEFAULT_SET.to_vec(),
+            prefer_server_cipher_order: false,
+            alpn: vec![],
+            ocsp: None,
+            early_data: false,
+            #[cfg(feature = "mtls")]
+            client_auth: ClientAuth::Off,
+        }
+    }
+
+    /// Creates a new `TlsConfig` from `certs` and `key` paths.
+    pub fn from_paths<C, K>(certs: C, key: K) -> Self
+        where C: AsRef<Path>, K: AsRef<Path>
+    {
+        TlsConfig {
+            certs: Either::Left(certs.as_ref().to_path_buf()),
+            key: Either::Left(key.as_ref().to_path_buf()),
+            ciphersuites: CipherSuite::DEFAULT_SET.to_vec(),
+            prefer_server_cipher_order: false,
+            alpn: vec![],
+            ocsp: None,
+            early_data: false,
+            #[cfg(feature = "mtls")]
+            client_auth: ClientAuth::Off,
+        }
+    }
+
+    /// Returns the configured certificate chain source.
+    pub fn certs(&self) -> Either<PathBuf, Vec<u8>> {
+        self.certs.clone()
+    }
+
+    /// Returns the configured private key source.
+    pub fn key(&self) -> Either<PathBuf, Vec<u8>> {
+        self.key.clone()
+    }
+
+    /// Sets the client authentication policy. See [`ClientAuth`].
+    #[cfg(feature = "mtls")]
+    pub fn with_client_auth(mut self, client_auth: ClientAuth) -> Self {
+        self.client_auth = client_auth;
+        self
+    }
+
+    /// Sets the ALPN protocols to advertise during the handshake, in order
+    /// of preference, e.g. `["h2", "http/1.1"]`. Defaults to empty, which
+    /// leaves ALPN negotiation up to `rustls`'s own defaults.
+    pub fn with_alpn<I, S>(mut self, protocols: I) -> Self
+        where I: IntoIterator<Item = S>, S: Into<String>
+    {
+        self.alpn = protocols.into_iter().map(|s| s.into()).collect();
+        self
+    }
+
+    /// Sets a DER-encoded OCSP response to staple to the certificate during
+    /// the handshake, letting clients skip an extra round-trip to the CA to
+    /// check revocation status.
+    ///
+    /// OCSP responses expire much sooner than certificates do; pair this
+    /// with [`TlsConfig::into_reloading_resolver()`] to keep the stapled
+    /// response fresh on its own schedule.
+    pub fn with_ocsp(mut self, ocsp: Either<PathBuf, Vec<u8>>) -> Self {
+        self.ocsp = Some(ocsp);
+        self
+    }
+
+    /// Enables TLS 1.3 0-RTT early data.
+    ///
+    /// Early data arrives before the handshake is complete and thus before
+    /// the client has proven it isn't replaying a captured connection
+    /// attempt, so it must never be trusted for non-idempotent requests.
+    /// Routes that accept it can check
+    /// [`Connection::is_early_data()`](crate::listener::Connection) and
+    /// reject replayable methods accordingly. Off by default.
+    pub fn with_early_data(mut self, early_data: bool) -> Self {
+        self.early_data = early_data;
+        self
+    }
+
+    /// Turns this configuration into a [`ReloadingResolver`] that watches
+    /// `certs`/`key` on disk and hot-reloads the TLS configuration when they
+    /// change, checking every 30 seconds.
+    ///
+    /// See [`ReloadingResolver`] for details.
+    pub fn into_reloading_resolver(self) -> Result<Arc<crate::tls::ReloadingResolver>, Error> {
+        self.into_reloading_resolver_with_interval(crate::tls::reload::DEFAULT_POLL_INTERVAL)
+    }
+
+    /// Like [`TlsConfig::into_reloading_resolver()`] but checks for changes
+    /// every `interval` instead of the default of 30 seconds.
+    pub fn into_reloading_resolver_with_interval(
+        self,
+        interval: std::time::Duration,
+    ) -> Result<Arc<crate::tls::ReloadingResolver>, Error> {
+        crate::tls::ReloadingResolver::spawn(self, interval)
+    }
+
+    /// Builds a [`rustls::ServerConfig`] from this configuration, reading
+    /// and parsing certificates, keys, and (if set) the mTLS CA bundle.
+    pub fn to_server_config(&self) -> Result<ServerConfig, Error> {
+        let cert_chain = util::load_certs(&util::read_bytes(&self.certs, "certificate chain")?)?;
+        let key = util::load_key(&util::read_bytes(&self.key, "private key")?)?;
+        let ocsp = self.ocsp.as_ref()
+            .map(|ocsp| util::read_bytes(ocsp, "OCSP response"))
+            .transpose()?
+            .unwrap_or_default();
+
+        let mut provider = rustls::crypto::ring::default_provider();
+        provider.cipher_suites.retain(|suite| {
+            self.ciphersuites.iter().any(|c| c.to_rustls() == suite.suite())
+        });
+
+        let provider = Arc::new(provider);
+        let verifier = self.client_cert_verifier(&provider)?;
+
+        let mut config = ServerConfig::builder_with_provider(provider)
+            .with_safe_default_protocol_versions()?
+            .with_client_cert_verifier(verifier)
+            .with_single_cert_with_ocsp(cert_chain, key, ocsp)?;
+
+        config.ignore_client_order = self.prefer_server_cipher_order;
+        config.max_early_data_size = if self.early_data { 16 * 1024 } else { 0 };
+        config.alpn_protocols = self.alpn.iter().map(|p| p.as_bytes().to_vec()).collect();
+        Ok(config)
+    }
+
+    #[cfg(feature = "mtls")]
+    fn client_cert_verifier(
+        &self,
+        provider: &Arc<rustls::crypto::CryptoProvider>,
+    ) -> Result<Arc<dyn rustls::server::danger::ClientCertVerifier>, Error> {
+        match &self.client_auth {
+            ClientAuth::Off => Ok(Arc::new(NoClientAuth)),
+            ClientAuth::Optional { ca } => {
+                let roots = util::load_ca_certs(&util::read_bytes(ca, "mTLS CA bundle")?)?;
+                Ok(WebPkiClientVerifier::builder_with_provider(roots.into(), provider.clone())
+                    .allow_unauthenticated()
+                    .build()?)
+            }
+            ClientAuth::Required { ca } => {
+                let roots = util::load_ca_certs(&util::read_bytes(ca, "mTLS CA bundle")?)?;
+                Ok(WebPkiClientVerifier::builder_with_provider(roots.into(), provider.clone())
+                    .build()?)
+            }
+        }
+    }
+
+    #[cfg(not(feature = "mtls"))]
+    fn client_cert_verifier(
+        &self,
+        _provider: &Arc<rustls::crypto::CryptoProvider>,
+    ) -> Result<Arc<dyn rustls::server::danger::ClientCertVerifier>, Error> {
+        Ok(Arc::new(NoClientAuth))
+    }
+}
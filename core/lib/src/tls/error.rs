@@ -0,0 +1,55 @@
+use std::io;
+
+use crate::tls::rustls;
+
+/// Type alias for `Result` with an error of [`tls::Error`](Error).
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Errors that can occur when configuring or binding TLS.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// An I/O error while reading a certificate, key, or other TLS input.
+    Io(io::Error, &'static str),
+    /// No certificates were found in the certificate chain.
+    NoCerts,
+    /// No private keys were found, or more than one was found, and the
+    /// single key to use could not be determined.
+    BadKeyCount(usize),
+    /// The private key is of an unsupported or unrecognized type.
+    UnknownKey,
+    /// An error from `rustls` while constructing a [`rustls::ServerConfig`].
+    Rustls(rustls::Error),
+    /// An error while binding the underlying listener.
+    Bind(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io(e, context) => write!(f, "I/O error while reading {context}: {e}"),
+            Error::NoCerts => write!(f, "no certificates found in chain"),
+            Error::BadKeyCount(n) => write!(f, "expected exactly one private key, found {n}"),
+            Error::UnknownKey => write!(f, "could not parse private key as RSA, PKCS8, or EC"),
+            Error::Rustls(e) => write!(f, "TLS configuration error: {e}"),
+            Error::Bind(e) => write!(f, "failed to bind TLS listener: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e, _) => Some(e),
+            Error::Rustls(e) => Some(e),
+            Error::Bind(e) => Some(&**e),
+            _ => None,
+        }
+    }
+}
+
+impl From<rustls::Error> for Error {
+    fn from(e: rustls::Error) -> Self {
+        Error::Rustls(e)
+    }
+}
@@ -1,5 +1,7 @@
 mod error;
 mod resolver;
+mod sni;
+pub(crate) mod reload;
 pub(crate) mod config;
 pub(crate) mod util;
 
@@ -7,5 +9,9 @@ pub use rustls;
 
 pub use error::Result;
 pub use config::{TlsConfig, CipherSuite};
+#[cfg(feature = "mtls")]
+pub use config::ClientAuth;
 pub use error::Error;
 pub use resolver::{Resolver, ClientHello, ServerConfig};
+pub use sni::{SniResolver, SniConfig};
+pub use reload::ReloadingResolver;
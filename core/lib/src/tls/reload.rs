@@ -0,0 +1,112 @@
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use arc_swap::ArcSwap;
+use either::Either;
+
+use crate::tls::{ClientHello, Error, Resolver, ServerConfig, TlsConfig};
+
+/// The default interval at which [`ReloadingResolver`] checks the
+/// certificate and key files on disk for changes.
+pub(crate) const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// A [`Resolver`] that watches a [`TlsConfig`]'s `certs` and `key` files on
+/// disk and hot-reloads the TLS configuration when they change, without
+/// touching the filesystem on the handshake path.
+///
+/// Unlike naively `stat`-ing the cert file inside `resolve()`, the watch
+/// happens on a background task; `resolve()` itself is a lock-free
+/// [`ArcSwap::load_full()`] and adds no I/O to any individual handshake.
+/// A reload that fails to parse is logged and the previously-loaded,
+/// still-valid configuration keeps serving connections.
+///
+/// Construct one with [`TlsConfig::into_reloading_resolver()`].
+pub struct ReloadingResolver {
+    current: ArcSwap<ServerConfig>,
+}
+
+impl ReloadingResolver {
+    /// Builds the initial `ServerConfig` from `config`, then spawns a
+    /// background task that re-checks `config`'s `certs`/`key` files every
+    /// `interval` and rebuilds the `ServerConfig` if either has changed.
+    ///
+    /// If `certs` or `key` is an in-memory byte buffer rather than a path,
+    /// there's nothing on disk to watch, so no background task is spawned;
+    /// the resolver simply always returns the initial configuration.
+    pub(crate) fn spawn(config: TlsConfig, interval: Duration) -> Result<Arc<Self>, Error> {
+        let initial = config.to_server_config()?;
+        let resolver = Arc::new(ReloadingResolver {
+            current: ArcSwap::new(Arc::new(initial)),
+        });
+
+        if let (Either::Left(_), Either::Left(_)) = (config.certs(), config.key()) {
+            let weak = Arc::downgrade(&resolver);
+            tokio::spawn(async move {
+                let mut last_modified = modified_at(&config).await;
+                let mut timer = tokio::time::interval(interval);
+                timer.tick().await;
+                loop {
+                    timer.tick().await;
+                    let Some(resolver) = weak.upgrade() else { break };
+
+                    let modified = modified_at(&config).await;
+                    if modified <= last_modified {
+                        continue;
+                    }
+
+                    match config.to_server_config() {
+                        Ok(new_config) => {
+                            resolver.current.store(Arc::new(new_config));
+                            last_modified = modified;
+                        }
+                        Err(e) => {
+                            crate::error_!("failed to reload TLS config, keeping old: {}", e);
+                        }
+                    }
+                }
+            });
+        }
+
+        Ok(resolver)
+    }
+}
+
+async fn modified_at(config: &TlsConfig) -> Option<SystemTime> {
+    let cert_path = config.certs().left()?;
+    let key_path = config.key().left()?;
+    let cert_modified = tokio::fs::metadata(cert_path).await.ok()?.modified().ok()?;
+    let key_modified = tokio::fs::metadata(key_path).await.ok()?.modified().ok()?;
+    let mut modified = cert_modified.max(key_modified);
+
+    // OCSP responses expire much sooner than certificates, so an operator
+    // refreshing only the stapled response (and not `certs`/`key`) still
+    // needs that change picked up.
+    if let Some(ocsp_path) = config.ocsp.as_ref().and_then(|ocsp| ocsp.as_ref().left()) {
+        if let Ok(ocsp_modified) = tokio::fs::metadata(ocsp_path).await.and_then(|m| m.modified()) {
+            modified = modified.max(ocsp_modified);
+        }
+    }
+
+    Some(modified)
+}
+
+#[crate::async_trait]
+impl Resolver for ReloadingResolver {
+    async fn resolve(&self, _: ClientHello<'_>) -> Option<Arc<ServerConfig>> {
+        Some(self.current.load_full())
+    }
+}
+
+// `ReloadingResolver::spawn()` hands back an `Arc<ReloadingResolver>`, not a
+// bare `ReloadingResolver`, because the background reload task holds a `Weak`
+// into the same allocation: unwrapping the `Arc` to get a by-value resolver
+// would drop the reloader out from under that task. `Resolver::fairing()`
+// takes `self` by value, so without this impl an `Arc<ReloadingResolver>`
+// could never be attached. Implementing it here lets callers write
+// `config.into_reloading_resolver()?.fairing().await` directly.
+#[crate::async_trait]
+impl Resolver for Arc<ReloadingResolver> {
+    async fn resolve(&self, hello: ClientHello<'_>) -> Option<Arc<ServerConfig>> {
+        ReloadingResolver::resolve(self, hello).await
+    }
+}
@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde::Deserialize;
+
+use crate::http::uri::Host;
+use crate::tls::{Error, TlsConfig, ServerConfig, Resolver, ClientHello};
+use crate::{fairing, Build, Rocket};
+
+/// A [`Resolver`] that selects a [`TlsConfig`] based on the SNI hostname the
+/// client offers during the handshake.
+///
+/// Build one from a map of hostname to [`TlsConfig`] with
+/// [`SniResolver::try_new()`]; each `TlsConfig` is validated and turned into
+/// a [`rustls::ServerConfig`](crate::tls::rustls::ServerConfig) eagerly, so
+/// a typo'd cert or key path surfaces as an ignite-time error instead of a
+/// handshake failure. When a client's SNI hostname isn't in the map, or the
+/// client doesn't send one at all, [`TlsListener`](crate::listener::tls::TlsListener)
+/// falls back to its default configuration.
+///
+/// For zero-code configuration from `Rocket.toml`, see [`SniConfig`].
+pub struct SniResolver {
+    map: HashMap<Host<'static>, Arc<ServerConfig>>,
+}
+
+impl SniResolver {
+    /// Builds a resolver from a map of hostname to `TlsConfig`, eagerly
+    /// constructing and validating a `ServerConfig` for each entry.
+    pub fn try_new(configs: HashMap<Host<'static>, TlsConfig>) -> Result<Self, Error> {
+        let map = configs.into_iter()
+            .map(|(host, config)| Ok((host, Arc::new(config.to_server_config()?))))
+            .collect::<Result<HashMap<_, _>, Error>>()?;
+
+        Ok(SniResolver { map })
+    }
+}
+
+#[crate::async_trait]
+impl Resolver for SniResolver {
+    async fn resolve(&self, hello: ClientHello<'_>) -> Option<Arc<ServerConfig>> {
+        let host = Host::parse(hello.server_name()?).ok()?;
+        self.map.get(&host).cloned()
+    }
+}
+
+/// A [`Figment`](crate::figment::Figment)-extractable `sni` configuration
+/// table, wiring up an [`SniResolver`] with zero Rust code.
+///
+/// ```toml
+/// [default.sni."api.rocket.rs"]
+/// certs = "private/api_rocket_rs_cert.pem"
+/// key = "private/api_rocket_rs_key.pem"
+///
+/// [default.sni."blob.rocket.rs"]
+/// certs = "private/blob_cert.pem"
+/// key = "private/blob_key.pem"
+/// ```
+///
+/// Attach [`SniConfig::fairing()`] to enable it:
+///
+/// ```rust,no_run
+/// # #[macro_use] extern crate rocket;
+/// use rocket::tls::SniConfig;
+///
+/// #[launch]
+/// fn rocket() -> _ {
+///     rocket::build().attach(SniConfig::fairing())
+/// }
+/// ```
+#[derive(Deserialize)]
+pub struct SniConfig {
+    sni: HashMap<Host<'static>, TlsConfig>,
+}
+
+impl SniConfig {
+    /// Returns a fairing that reads the `sni` table from the active figment
+    /// at ignite-time and, if present, manages an [`SniResolver`] built from
+    /// it.
+    pub fn fairing() -> impl fairing::Fairing {
+        SniFairing
+    }
+}
+
+struct SniFairing;
+
+#[crate::async_trait]
+impl fairing::Fairing for SniFairing {
+    fn info(&self) -> fairing::Info {
+        fairing::Info {
+            name: "SNI TLS Resolver",
+            kind: fairing::Kind::Ignite | fairing::Kind::Singleton
+        }
+    }
+
+    async fn on_ignite(&self, rocket: Rocket<Build>) -> fairing::Result {
+        if !rocket.figment().contains("sni") {
+            return Ok(rocket);
+        }
+
+        let config: SniConfig = match rocket.figment().extract() {
+            Ok(config) => config,
+            Err(e) => {
+                crate::error_!("failed to extract `sni` config: {}", e);
+                return Err(rocket);
+            }
+        };
+
+        let resolver = match SniResolver::try_new(config.sni) {
+            Ok(resolver) => resolver,
+            Err(e) => {
+                crate::error_!("failed to build SNI resolver: {}", e);
+                return Err(rocket);
+            }
+        };
+
+        // Route through the same `resolver::Fairing` every other `Resolver`
+        // registers with, rather than managing a bare `Arc<dyn Resolver>`
+        // that nothing else would know to look for.
+        fairing::Fairing::on_ignite(&resolver.fairing().await, rocket).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SniConfig;
+    use crate::http::uri::Host;
+
+    #[test]
+    fn test_config() {
+        figment::Jail::expect_with(|jail| {
+            use crate::fs::relative;
+            use figment::Figment;
+            use figment::providers::{Toml, Format};
+
+            let cert_path = relative!("../../examples/tls/private/rsa_sha256_cert.pem");
+            let key_path = relative!("../../examples/tls/private/rsa_sha256_key.pem");
+
+            jail.create_file("Rocket.toml", &format!(r#"
+                [default.sni."api.rocket.rs"]
+                certs = "{cert_path}"
+                key = "{key_path}"
+
+                [default.sni."blob.rocket.rs"]
+                certs = "{cert_path}"
+                key = "{key_path}"
+            "#))?;
+
+            let toml = Toml::file("Rocket.toml").nested();
+            let config: SniConfig = Figment::from(toml).extract().unwrap();
+            assert!(config.sni.contains_key(&Host::parse("api.rocket.rs").unwrap()));
+            assert!(config.sni.contains_key(&Host::parse("blob.rocket.rs").unwrap()));
+            Ok(())
+        });
+    }
+}
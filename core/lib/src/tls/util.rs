@@ -0,0 +1,54 @@
+use std::io::BufReader;
+use std::fs;
+use std::path::Path;
+
+use either::Either;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+
+use crate::tls::Error;
+
+/// Reads the bytes behind an `Either<impl AsRef<Path>, Vec<u8>>`, either from
+/// disk or directly from the in-memory bundle, tagging I/O errors with
+/// `context` for [`Error::Io`].
+pub fn read_bytes<P: AsRef<Path>>(
+    source: &Either<P, Vec<u8>>,
+    context: &'static str,
+) -> Result<Vec<u8>, Error> {
+    match source {
+        Either::Left(path) => fs::read(path).map_err(|e| Error::Io(e, context)),
+        Either::Right(bytes) => Ok(bytes.clone()),
+    }
+}
+
+/// Parses a PEM-encoded certificate chain.
+pub fn load_certs(pem: &[u8]) -> Result<Vec<CertificateDer<'static>>, Error> {
+    let mut reader = BufReader::new(pem);
+    let certs = rustls_pemfile::certs(&mut reader)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| Error::Io(e, "certificate chain"))?;
+
+    if certs.is_empty() {
+        return Err(Error::NoCerts);
+    }
+
+    Ok(certs)
+}
+
+/// Parses a PEM-encoded private key, accepting RSA, PKCS8, or SEC1 (EC) keys.
+pub fn load_key(pem: &[u8]) -> Result<PrivateKeyDer<'static>, Error> {
+    let mut reader = BufReader::new(pem);
+    let keys = rustls_pemfile::private_key(&mut reader)
+        .map_err(|e| Error::Io(e, "private key"))?;
+
+    keys.ok_or(Error::UnknownKey)
+}
+
+/// Builds a `RootCertStore` from a PEM-encoded CA certificate bundle.
+pub fn load_ca_certs(pem: &[u8]) -> Result<rustls::RootCertStore, Error> {
+    let mut store = rustls::RootCertStore::empty();
+    for cert in load_certs(pem)? {
+        store.add(cert)?;
+    }
+
+    Ok(store)
+}